@@ -0,0 +1,40 @@
+//!
+//! # Hdl21 Batch Elaboration
+//!
+//! Elaborating and netlisting independent `Module`s is embarrassingly
+//! parallel, so batch entry points drop the GIL for the compute phase and
+//! fan the work out across threads with rayon.
+//!
+
+use rayon::prelude::*;
+
+use crate::errors::MyRustError;
+use crate::model::Module;
+use crate::netlist::{self, NetlistFormat};
+
+/// Elaborate a single module and render it to SPICE netlist text.
+///
+/// This is the per-module unit of work parallelized by `elaborate_all`; a
+/// real elaborator would resolve parameters and flatten hierarchy before
+/// handing off to the netlister.
+pub fn elaborate_one(module: &Module) -> Result<String, MyRustError> {
+    for instance in &module.instances {
+        if instance.of.is_empty() {
+            return Err(MyRustError::Elaboration(format!(
+                "instance '{}' on module '{}' has no target module",
+                instance.name, module.name
+            )));
+        }
+    }
+
+    netlist::netlist(module, NetlistFormat::Spice)
+}
+
+/// Elaborate a batch of modules in parallel, off the GIL.
+///
+/// The caller is responsible for extracting owned `Module`s from Python
+/// before calling this, and for converting the resulting `String`s back
+/// into Python objects afterward.
+pub fn elaborate_all(modules: &[Module]) -> Result<Vec<String>, MyRustError> {
+    modules.par_iter().map(elaborate_one).collect()
+}
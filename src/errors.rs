@@ -0,0 +1,68 @@
+//!
+//! # Hdl21 Error Types
+//!
+//! Defines the Rust-side error type used throughout the crate, and the
+//! Python exception hierarchy it is bridged to via `PyErr`.
+//!
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::PyErr;
+
+// Root of the `hdl21` Python exception hierarchy.
+create_exception!(hdl21, Hdl21Error, PyException);
+// Raised for failures during module elaboration.
+create_exception!(hdl21, ElaborationError, Hdl21Error);
+// Raised for invalid or inconsistent signal connections.
+create_exception!(hdl21, ConnectionError, Hdl21Error);
+// Raised for failures generating a netlist.
+create_exception!(hdl21, NetlistError, Hdl21Error);
+// Raised for failures during (de)serialization of the data model.
+create_exception!(hdl21, SerializationError, Hdl21Error);
+
+/// Rust-side error type for all fallible operations in this crate.
+///
+/// Converts to the matching Python exception subclass via `From<MyRustError> for PyErr`,
+/// so Rust functions can simply return `Result<T, MyRustError>` and let `?` do the rest.
+#[derive(Debug, Clone)]
+pub enum MyRustError {
+    Elaboration(String),
+    Connection(String),
+    Netlist(String),
+    Serialization(String),
+}
+
+impl std::fmt::Display for MyRustError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MyRustError::Elaboration(msg) => write!(f, "ElaborationError: {}", msg),
+            MyRustError::Connection(msg) => write!(f, "ConnectionError: {}", msg),
+            MyRustError::Netlist(msg) => write!(f, "NetlistError: {}", msg),
+            MyRustError::Serialization(msg) => write!(f, "SerializationError: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for MyRustError {}
+
+impl From<MyRustError> for PyErr {
+    fn from(err: MyRustError) -> PyErr {
+        match err {
+            MyRustError::Elaboration(msg) => ElaborationError::new_err(msg),
+            MyRustError::Connection(msg) => ConnectionError::new_err(msg),
+            MyRustError::Netlist(msg) => NetlistError::new_err(msg),
+            MyRustError::Serialization(msg) => SerializationError::new_err(msg),
+        }
+    }
+}
+
+/// Register the `hdl21` exception hierarchy on the Python module `m`.
+pub fn register(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("Hdl21Error", py.get_type::<Hdl21Error>())?;
+    m.add("ElaborationError", py.get_type::<ElaborationError>())?;
+    m.add("ConnectionError", py.get_type::<ConnectionError>())?;
+    m.add("NetlistError", py.get_type::<NetlistError>())?;
+    m.add("SerializationError", py.get_type::<SerializationError>())?;
+    Ok(())
+}
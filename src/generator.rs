@@ -0,0 +1,74 @@
+//!
+//! # Hdl21 Generator Sandbox
+//!
+//! Compiles and executes a user-supplied Hdl21 generator snippet via
+//! `PyModule::from_code`, invoking its entry function with `params` and
+//! extracting the resulting `Module` back into the native data model.
+//! This gives a controlled evaluation path for parametric generators in
+//! server/CLI contexts where generator source arrives as a string.
+//!
+//! Calling convention: the snippet must define `generate(params)`,
+//! receiving the caller's `params` dict as its sole positional argument.
+//!
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::MyRustError;
+use crate::model::Module;
+
+/// The entry function a generator snippet must define.
+const ENTRY_FN: &str = "generate";
+
+lazy_static::lazy_static! {
+    // Caches the compiled module object by source hash, so repeated
+    // calls with different `params` skip recompilation entirely.
+    static ref COMPILED_CACHE: Mutex<HashMap<u64, Py<PyModule>>> = Mutex::new(HashMap::new());
+}
+
+fn source_hash(source: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Compile (or fetch the cached compilation of) `source`, then call its
+/// `generate(params)` entry function and extract the resulting `Module`.
+pub fn run_generator(py: Python, source: &str, params: &PyDict) -> PyResult<Module> {
+    let hash = source_hash(source);
+
+    let py_module = {
+        let mut cache = COMPILED_CACHE.lock().unwrap();
+        match cache.get(&hash) {
+            Some(cached) => cached.clone_ref(py),
+            None => {
+                let module_name = format!("hdl21_generator_{}", hash);
+                let compiled: Py<PyModule> =
+                    PyModule::from_code(py, source, "<hdl21 generator>", &module_name)?.into();
+                cache.insert(hash, compiled.clone_ref(py));
+                compiled
+            }
+        }
+    };
+    let py_module = py_module.as_ref(py);
+
+    let entry = py_module.getattr(ENTRY_FN).map_err(|_| {
+        PyErr::from(MyRustError::Elaboration(format!(
+            "generator source has no entry function '{}'",
+            ENTRY_FN
+        )))
+    })?;
+    let result = entry.call1((params,))?;
+
+    result.extract::<Module>().map_err(|_| {
+        PyErr::from(MyRustError::Elaboration(format!(
+            "generator function '{}' did not return a Module",
+            ENTRY_FN
+        )))
+    })
+}
@@ -2,21 +2,72 @@
 //! # Hdl21 Python Bindings
 //!
 
-use pyo3::exceptions::RuntimeError;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
-use pyo3::{PyErr, PyResult};
+use pyo3::types::{PyBytes, PyDict};
+use pyo3::PyResult;
 
+mod batch;
+mod errors;
+mod generator;
+mod model;
+mod netlist;
+mod serialize;
+
+use model::{Instance, Module, Signal};
+use netlist::NetlistFormat;
 
 // Note "hdl21" must be the name of the `.so` or `.pyd` file,
 // i.e. it must be the `package` and/or `lib` name in Cargo.toml
 
 #[pymodule]
-fn hdl21(_py: Python, m: &PyModule) -> PyResult<()> {
+fn hdl21(py: Python, m: &PyModule) -> PyResult<()> {
+    errors::register(py, m)?;
+
+    m.add_class::<Module>()?;
+    m.add_class::<Signal>()?;
+    m.add_class::<Instance>()?;
+
     /// "Health Check"
     #[pyfn(m, "health")]
     fn health_py(_py: Python) -> PyResult<String> {
         Ok("alive".to_string())
     }
+
+    /// Serialize `module` to its compact binary wire format.
+    #[pyfn(m, "dump")]
+    fn dump_py(py: Python, module: &Module) -> PyResult<Py<PyBytes>> {
+        let bytes = serialize::encode(module);
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    /// Deserialize a `Module` previously produced by `dump`.
+    #[pyfn(m, "load")]
+    fn load_py(_py: Python, bytes: &PyBytes) -> PyResult<Module> {
+        Ok(serialize::decode(bytes.as_bytes())?)
+    }
+
+    /// Elaborate and netlist a batch of modules in parallel, releasing
+    /// the GIL for the duration of the compute phase.
+    #[pyfn(m, "netlist_all")]
+    fn netlist_all_py(py: Python, modules: Vec<Module>) -> PyResult<Vec<String>> {
+        Ok(py.allow_threads(|| batch::elaborate_all(&modules))?)
+    }
+
+    /// Emit `module` as netlist text in the given dialect (`"spice"`,
+    /// `"spectre"`, or `"verilog"`).
+    #[pyfn(m, "netlist")]
+    fn netlist_py(_py: Python, module: &Module, fmt: Option<&str>) -> PyResult<String> {
+        let fmt: NetlistFormat = fmt.unwrap_or("spice").parse().map_err(pyo3::PyErr::from)?;
+        Ok(netlist::netlist(module, fmt)?)
+    }
+
+    /// Compile and run a user-supplied generator snippet in an embedded
+    /// sandbox, calling its `generate(params)` entry function and
+    /// returning the `Module` it produces.
+    #[pyfn(m, "run_generator")]
+    fn run_generator_py(py: Python, source: &str, params: &PyDict) -> PyResult<Module> {
+        generator::run_generator(py, source, params)
+    }
+
     Ok(())
 }
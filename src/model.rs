@@ -0,0 +1,215 @@
+//!
+//! # Hdl21 Native Data Model
+//!
+//! Rust-backed circuit primitives, exposed to Python as `#[pyclass]` types.
+//! Keeping the hot data model (signals, instances, connections) in native
+//! structs makes construction and iteration over large hierarchies far
+//! cheaper than the equivalent pure-Python objects.
+//!
+
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+
+use crate::errors::MyRustError;
+
+/// A single wire (or bus) within a `Module`.
+///
+/// `is_port` marks a signal as part of the module's own interface, as
+/// opposed to an internal net — the netlister needs this distinction to
+/// emit the definition's port/interface list (`.subckt name p1 p2 ...`,
+/// `module name(p1, p2);`, etc).
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct Signal {
+    #[pyo3(get, set)]
+    pub name: String,
+    #[pyo3(get, set)]
+    pub width: usize,
+    #[pyo3(get, set)]
+    pub is_port: bool,
+}
+
+#[pymethods]
+impl Signal {
+    #[new]
+    pub(crate) fn new(name: String, width: usize, is_port: bool) -> Self {
+        Self {
+            name,
+            width,
+            is_port,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Signal(name='{}', width={}, is_port={})",
+            self.name, self.width, self.is_port
+        )
+    }
+}
+
+/// A sub-circuit instance, referencing its `Module` by name.
+///
+/// `ports` is the instance's declared, ordered port list — the order in
+/// which the netlister resolves connected signals for positional netlist
+/// formats (SPICE/Spectre/Verilog all connect by port position, not name).
+/// `connect` calls may be made in any order.
+/// `params` are instance-parameter overrides (e.g. `w`, `l`), keyed by
+/// name, rendered in dialect-specific syntax by the netlister.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct Instance {
+    #[pyo3(get, set)]
+    pub name: String,
+    #[pyo3(get, set)]
+    pub of: String,
+    #[pyo3(get)]
+    pub ports: Vec<String>,
+    #[pyo3(get)]
+    pub params: HashMap<String, String>,
+}
+
+#[pymethods]
+impl Instance {
+    #[new]
+    pub(crate) fn new(
+        name: String,
+        of: String,
+        ports: Vec<String>,
+        params: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            name,
+            of,
+            ports,
+            params,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Instance(name='{}', of='{}', ports={:?}, params={:?})",
+            self.name, self.of, self.ports, self.params
+        )
+    }
+}
+
+/// A circuit module: a set of signals, child instances, and the
+/// connections between instance ports and module signals.
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+pub struct Module {
+    #[pyo3(get, set)]
+    pub name: String,
+    pub signals: Vec<Signal>,
+    pub instances: Vec<Instance>,
+    // Keyed by "<instance name>.<port name>", valued by connected signal name.
+    pub connections: HashMap<String, String>,
+}
+
+#[pymethods]
+impl Module {
+    #[new]
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Module(name='{}', signals={}, instances={})",
+            self.name,
+            self.signals.len(),
+            self.instances.len()
+        )
+    }
+
+    /// Add a `Signal` to the module.
+    pub(crate) fn add_signal(&mut self, signal: Signal) {
+        self.signals.push(signal);
+    }
+
+    /// Add an `Instance` to the module.
+    pub(crate) fn add_instance(&mut self, instance: Instance) {
+        self.instances.push(instance);
+    }
+
+    /// Connect `instance_name.port` to `signal`, failing if the instance,
+    /// its declared `port`, or the `signal` does not exist on this module.
+    pub(crate) fn connect(&mut self, instance_name: &str, port: &str, signal: &str) -> PyResult<()> {
+        let instance = self
+            .instances
+            .iter()
+            .find(|inst| inst.name == instance_name)
+            .ok_or_else(|| {
+                MyRustError::Connection(format!(
+                    "no such instance '{}' on module '{}'",
+                    instance_name, self.name
+                ))
+            })?;
+        if !instance.ports.iter().any(|p| p == port) {
+            return Err(MyRustError::Connection(format!(
+                "instance '{}' on module '{}' has no port '{}'",
+                instance_name, self.name, port
+            ))
+            .into());
+        }
+        if !self.signals.iter().any(|sig| sig.name == signal) {
+            return Err(MyRustError::Connection(format!(
+                "no such signal '{}' on module '{}'",
+                signal, self.name
+            ))
+            .into());
+        }
+        self.connections
+            .insert(format!("{}.{}", instance_name, port), signal.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_rejects_unknown_instance() {
+        let mut module = Module::new("top".to_string());
+        module.add_signal(Signal::new("net1".to_string(), 1, false));
+
+        let err = module.connect("missing", "d", "net1").unwrap_err();
+        assert!(err.to_string().contains("no such instance"));
+    }
+
+    #[test]
+    fn connect_rejects_undeclared_port() {
+        let mut module = Module::new("top".to_string());
+        module.add_signal(Signal::new("net1".to_string(), 1, false));
+        module.add_instance(Instance::new(
+            "x1".to_string(),
+            "nmos".to_string(),
+            vec!["d".to_string(), "g".to_string()],
+            HashMap::new(),
+        ));
+
+        let err = module.connect("x1", "s", "net1").unwrap_err();
+        assert!(err.to_string().contains("no port"));
+    }
+
+    #[test]
+    fn connect_records_the_signal_for_a_declared_port() {
+        let mut module = Module::new("top".to_string());
+        module.add_signal(Signal::new("net1".to_string(), 1, false));
+        module.add_instance(Instance::new(
+            "x1".to_string(),
+            "nmos".to_string(),
+            vec!["d".to_string()],
+            HashMap::new(),
+        ));
+
+        module.connect("x1", "d", "net1").unwrap();
+        assert_eq!(module.connections.get("x1.d"), Some(&"net1".to_string()));
+    }
+}
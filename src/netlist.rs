@@ -0,0 +1,272 @@
+//!
+//! # Hdl21 Netlist Emission
+//!
+//! Walks the native data model and emits netlist text for a selectable
+//! dialect. Doing this over the native structs in Rust avoids per-line
+//! Python string-building overhead and keeps dialect differences (comment
+//! characters, port separators, parameter syntax) behind one trait. Every
+//! line is emitted whole; no dialect uses line continuation.
+//!
+
+use std::str::FromStr;
+
+use crate::errors::MyRustError;
+use crate::model::{Instance, Module};
+
+/// Netlist dialects supported by [`netlist`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetlistFormat {
+    Spice,
+    Spectre,
+    Verilog,
+}
+
+impl FromStr for NetlistFormat {
+    type Err = MyRustError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spice" => Ok(NetlistFormat::Spice),
+            "spectre" => Ok(NetlistFormat::Spectre),
+            "verilog" => Ok(NetlistFormat::Verilog),
+            other => Err(MyRustError::Netlist(format!(
+                "unknown netlist format '{}'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Dialect-specific emission, one implementor per `NetlistFormat`.
+trait Dialect {
+    /// Line comment prefix, e.g. `*` for SPICE or `//` for Verilog.
+    fn comment(&self) -> &'static str;
+    /// Separator between positionally-connected nets on an instance line.
+    fn port_sep(&self) -> &'static str;
+    /// Emit the full text for `module`.
+    fn emit(&self, module: &Module) -> Result<String, MyRustError>;
+}
+
+struct SpiceDialect;
+
+impl Dialect for SpiceDialect {
+    fn comment(&self) -> &'static str {
+        "*"
+    }
+
+    fn port_sep(&self) -> &'static str {
+        " "
+    }
+
+    fn emit(&self, module: &Module) -> Result<String, MyRustError> {
+        let mut out = format!("{} module: {}\n", self.comment(), module.name);
+        out.push_str(&format!(
+            ".subckt {} {}\n",
+            module.name,
+            interface_ports(module).join(" ")
+        ));
+        for instance in &module.instances {
+            let ports = resolve_ports(module, instance, self.port_sep())?;
+            let params = format_params_kv(instance);
+            out.push_str(&format!(
+                "X{} {} {}{}\n",
+                instance.name, ports, instance.of, params
+            ));
+        }
+        out.push_str(".ends\n");
+        Ok(out)
+    }
+}
+
+struct SpectreDialect;
+
+impl Dialect for SpectreDialect {
+    fn comment(&self) -> &'static str {
+        "//"
+    }
+
+    fn port_sep(&self) -> &'static str {
+        " "
+    }
+
+    fn emit(&self, module: &Module) -> Result<String, MyRustError> {
+        let mut out = format!("{} module: {}\n", self.comment(), module.name);
+        out.push_str(&format!(
+            "subckt {} {}\n",
+            module.name,
+            interface_ports(module).join(" ")
+        ));
+        for instance in &module.instances {
+            let ports = resolve_ports(module, instance, self.port_sep())?;
+            let params = format_params_kv(instance);
+            out.push_str(&format!(
+                "{} ({}) {}{}\n",
+                instance.name, ports, instance.of, params
+            ));
+        }
+        out.push_str("ends\n");
+        Ok(out)
+    }
+}
+
+struct VerilogDialect;
+
+impl Dialect for VerilogDialect {
+    fn comment(&self) -> &'static str {
+        "//"
+    }
+
+    fn port_sep(&self) -> &'static str {
+        ", "
+    }
+
+    fn emit(&self, module: &Module) -> Result<String, MyRustError> {
+        let mut out = format!("{} module: {}\n", self.comment(), module.name);
+        out.push_str(&format!(
+            "module {}({});\n",
+            module.name,
+            interface_ports(module).join(", ")
+        ));
+        for instance in &module.instances {
+            let ports = resolve_ports(module, instance, self.port_sep())?;
+            let params = format_params_verilog(instance);
+            out.push_str(&format!(
+                "{}{} {} ({});\n",
+                instance.of, params, instance.name, ports
+            ));
+        }
+        out.push_str("endmodule\n");
+        Ok(out)
+    }
+}
+
+/// The module's own port/interface signals, in declaration order, for the
+/// definition header (`.subckt name p1 p2`, `module name(p1, p2);`, ...).
+fn interface_ports(module: &Module) -> Vec<&str> {
+    module
+        .signals
+        .iter()
+        .filter(|signal| signal.is_port)
+        .map(|signal| signal.name.as_str())
+        .collect()
+}
+
+/// Resolve the connected signal for each of `instance`'s declared ports,
+/// in its declared (positional) order, joined with `sep`. SPICE/Spectre
+/// connect by whitespace, Verilog by comma — hard-coding either separator
+/// in this shared helper would make the other dialect's output invalid.
+fn resolve_ports(module: &Module, instance: &Instance, sep: &str) -> Result<String, MyRustError> {
+    instance
+        .ports
+        .iter()
+        .map(|port| {
+            let key = format!("{}.{}", instance.name, port);
+            module.connections.get(&key).cloned().ok_or_else(|| {
+                MyRustError::Netlist(format!(
+                    "port '{}' of instance '{}' on module '{}' is unconnected",
+                    port, instance.name, module.name
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|signals| signals.join(sep))
+}
+
+/// Sorted `(key, value)` pairs for an instance's parameters, for stable
+/// output independent of `HashMap` iteration order.
+fn sorted_params(instance: &Instance) -> Vec<(&str, &str)> {
+    let mut params: Vec<(&str, &str)> = instance
+        .params
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    params.sort_by_key(|(key, _)| *key);
+    params
+}
+
+/// SPICE/Spectre instance-parameter syntax: ` key=value key=value`.
+fn format_params_kv(instance: &Instance) -> String {
+    sorted_params(instance)
+        .into_iter()
+        .map(|(key, value)| format!(" {}={}", key, value))
+        .collect()
+}
+
+/// Verilog instance-parameter syntax: ` #(.key(value), .key(value))`.
+fn format_params_verilog(instance: &Instance) -> String {
+    let params = sorted_params(instance);
+    if params.is_empty() {
+        return String::new();
+    }
+    let bindings: Vec<String> = params
+        .into_iter()
+        .map(|(key, value)| format!(".{}({})", key, value))
+        .collect();
+    format!(" #({})", bindings.join(", "))
+}
+
+fn dialect_for(fmt: NetlistFormat) -> Box<dyn Dialect> {
+    match fmt {
+        NetlistFormat::Spice => Box::new(SpiceDialect),
+        NetlistFormat::Spectre => Box::new(SpectreDialect),
+        NetlistFormat::Verilog => Box::new(VerilogDialect),
+    }
+}
+
+/// Emit `module` as netlist text in the given dialect.
+pub fn netlist(module: &Module, fmt: NetlistFormat) -> Result<String, MyRustError> {
+    dialect_for(fmt).emit(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::model::Signal;
+
+    fn amp() -> Module {
+        let mut params = HashMap::new();
+        params.insert("w".to_string(), "1u".to_string());
+
+        let mut module = Module::new("amp".to_string());
+        module.add_signal(Signal::new("inp".to_string(), 1, true));
+        module.add_signal(Signal::new("out".to_string(), 1, true));
+        module.add_instance(Instance::new(
+            "m1".to_string(),
+            "nmos".to_string(),
+            vec!["d".to_string(), "g".to_string()],
+            params,
+        ));
+        module.connect("m1", "d", "out").unwrap();
+        module.connect("m1", "g", "inp").unwrap();
+        module
+    }
+
+    #[test]
+    fn verilog_instance_ports_are_comma_separated() {
+        let text = netlist(&amp(), NetlistFormat::Verilog).unwrap();
+        assert!(text.contains("nmos #(.w(1u)) m1 (out, inp);"));
+        assert!(text.contains("module amp(inp, out);"));
+    }
+
+    #[test]
+    fn spice_instance_ports_are_space_separated() {
+        let text = netlist(&amp(), NetlistFormat::Spice).unwrap();
+        assert!(text.contains("Xm1 out inp nmos w=1u"));
+        assert!(text.contains(".subckt amp inp out"));
+    }
+
+    #[test]
+    fn unconnected_port_is_a_netlist_error() {
+        let mut module = Module::new("amp".to_string());
+        module.add_instance(Instance::new(
+            "m1".to_string(),
+            "nmos".to_string(),
+            vec!["d".to_string()],
+            HashMap::new(),
+        ));
+        let err = netlist(&module, NetlistFormat::Spice).unwrap_err();
+        assert!(err.to_string().contains("unconnected"));
+    }
+}
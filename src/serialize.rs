@@ -0,0 +1,199 @@
+//!
+//! # Hdl21 Binary (De)Serialization
+//!
+//! A compact, length-prefixed, field-tagged wire format for the native
+//! `Module` data model. This is intentionally simple protobuf-style
+//! encoding (varint-free, fixed tag bytes) rather than a full protobuf
+//! implementation, giving a fast, language-neutral on-disk format without
+//! pulling in pickling of Python objects.
+//!
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use crate::errors::MyRustError;
+use crate::model::{Instance, Module, Signal};
+
+// Field tags in the wire format.
+const TAG_NAME: u8 = 1;
+const TAG_SIGNAL: u8 = 2;
+const TAG_INSTANCE: u8 = 3;
+const TAG_CONNECTION: u8 = 4;
+
+fn write_lenstr(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn read_lenstr(buf: &[u8], pos: &mut usize) -> Result<String, MyRustError> {
+    let len_bytes: [u8; 4] = buf
+        .get(*pos..*pos + 4)
+        .ok_or_else(|| MyRustError::Serialization("truncated length prefix".to_string()))?
+        .try_into()
+        .unwrap();
+    let len = u32::from_le_bytes(len_bytes) as usize;
+    *pos += 4;
+    let s = buf
+        .get(*pos..*pos + len)
+        .ok_or_else(|| MyRustError::Serialization("truncated string field".to_string()))?;
+    *pos += len;
+    String::from_utf8(s.to_vec())
+        .map_err(|e| MyRustError::Serialization(format!("invalid utf-8: {}", e)))
+}
+
+fn read_u32(buf: &[u8], pos: &mut usize, what: &str) -> Result<u32, MyRustError> {
+    let bytes: [u8; 4] = buf
+        .get(*pos..*pos + 4)
+        .ok_or_else(|| MyRustError::Serialization(format!("truncated {}", what)))?
+        .try_into()
+        .unwrap();
+    *pos += 4;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Encode `module` into the compact wire format.
+pub fn encode(module: &Module) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    buf.push(TAG_NAME);
+    write_lenstr(&mut buf, &module.name);
+
+    for signal in &module.signals {
+        buf.push(TAG_SIGNAL);
+        write_lenstr(&mut buf, &signal.name);
+        buf.extend_from_slice(&(signal.width as u32).to_le_bytes());
+        buf.push(signal.is_port as u8);
+    }
+
+    for instance in &module.instances {
+        buf.push(TAG_INSTANCE);
+        write_lenstr(&mut buf, &instance.name);
+        write_lenstr(&mut buf, &instance.of);
+        buf.extend_from_slice(&(instance.ports.len() as u32).to_le_bytes());
+        for port in &instance.ports {
+            write_lenstr(&mut buf, port);
+        }
+        buf.extend_from_slice(&(instance.params.len() as u32).to_le_bytes());
+        for (key, value) in &instance.params {
+            write_lenstr(&mut buf, key);
+            write_lenstr(&mut buf, value);
+        }
+    }
+
+    for (key, signal) in &module.connections {
+        buf.push(TAG_CONNECTION);
+        write_lenstr(&mut buf, key);
+        write_lenstr(&mut buf, signal);
+    }
+
+    buf
+}
+
+/// Decode a `Module` from bytes previously produced by `encode`.
+pub fn decode(bytes: &[u8]) -> Result<Module, MyRustError> {
+    let mut pos = 0;
+    let mut module = Module::new(String::new());
+
+    while pos < bytes.len() {
+        let tag = bytes[pos];
+        pos += 1;
+        match tag {
+            TAG_NAME => {
+                module.name = read_lenstr(bytes, &mut pos)?;
+            }
+            TAG_SIGNAL => {
+                let name = read_lenstr(bytes, &mut pos)?;
+                let width_bytes: [u8; 4] = bytes
+                    .get(pos..pos + 4)
+                    .ok_or_else(|| {
+                        MyRustError::Serialization("truncated signal width".to_string())
+                    })?
+                    .try_into()
+                    .unwrap();
+                pos += 4;
+                let is_port = *bytes.get(pos).ok_or_else(|| {
+                    MyRustError::Serialization("truncated signal is_port flag".to_string())
+                })? != 0;
+                pos += 1;
+                module.signals.push(Signal {
+                    name,
+                    width: u32::from_le_bytes(width_bytes) as usize,
+                    is_port,
+                });
+            }
+            TAG_INSTANCE => {
+                let name = read_lenstr(bytes, &mut pos)?;
+                let of = read_lenstr(bytes, &mut pos)?;
+                let port_count = read_u32(bytes, &mut pos, "instance port count")?;
+                let mut ports = Vec::with_capacity(port_count as usize);
+                for _ in 0..port_count {
+                    ports.push(read_lenstr(bytes, &mut pos)?);
+                }
+                let param_count = read_u32(bytes, &mut pos, "instance param count")?;
+                let mut params = HashMap::with_capacity(param_count as usize);
+                for _ in 0..param_count {
+                    let key = read_lenstr(bytes, &mut pos)?;
+                    let value = read_lenstr(bytes, &mut pos)?;
+                    params.insert(key, value);
+                }
+                module.instances.push(Instance {
+                    name,
+                    of,
+                    ports,
+                    params,
+                });
+            }
+            TAG_CONNECTION => {
+                let key = read_lenstr(bytes, &mut pos)?;
+                let signal = read_lenstr(bytes, &mut pos)?;
+                module.connections.insert(key, signal);
+            }
+            other => {
+                return Err(MyRustError::Serialization(format!(
+                    "unrecognized field tag {}",
+                    other
+                )))
+            }
+        }
+    }
+
+    Ok(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Signal;
+
+    #[test]
+    fn round_trips_a_module() {
+        let mut params = HashMap::new();
+        params.insert("w".to_string(), "1u".to_string());
+
+        let mut module = Module::new("top".to_string());
+        module.add_signal(Signal::new("net1".to_string(), 1, true));
+        module.add_instance(Instance::new(
+            "x1".to_string(),
+            "nmos".to_string(),
+            vec!["d".to_string(), "g".to_string()],
+            params,
+        ));
+        module.connect("x1", "d", "net1").unwrap();
+
+        let decoded = decode(&encode(&module)).unwrap();
+
+        assert_eq!(decoded.name, module.name);
+        assert_eq!(decoded.signals.len(), 1);
+        assert_eq!(decoded.signals[0].name, "net1");
+        assert_eq!(decoded.signals[0].width, 1);
+        assert!(decoded.signals[0].is_port);
+        assert_eq!(decoded.instances.len(), 1);
+        assert_eq!(decoded.instances[0].name, "x1");
+        assert_eq!(decoded.instances[0].ports, vec!["d", "g"]);
+        assert_eq!(
+            decoded.instances[0].params.get("w"),
+            Some(&"1u".to_string())
+        );
+        assert_eq!(decoded.connections.get("x1.d"), Some(&"net1".to_string()));
+    }
+}